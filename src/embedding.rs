@@ -0,0 +1,44 @@
+/// Dimensionality of the vectors stored in Qdrant. Must match the
+/// collection's configured vector size.
+pub const VECTOR_SIZE: u64 = 256;
+
+/// Turns free text into a fixed-size embedding vector.
+///
+/// This is a lightweight, dependency-free stand-in for a real embedding
+/// model: each token is hashed into a bucket and accumulated, then the
+/// result is L2-normalized so cosine similarity behaves sensibly. Swap
+/// this out for a call to a proper embedding model/service once one is
+/// wired up; the rest of the pipeline only depends on getting back a
+/// `Vec<f32>` of length `VECTOR_SIZE`.
+pub fn embed(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0f32; VECTOR_SIZE as usize];
+
+    for token in text.split_whitespace() {
+        let bucket = (fnv1a(token.to_lowercase().as_bytes()) as usize) % vector.len();
+        vector[bucket] += 1.0;
+    }
+
+    normalize(&mut vector);
+    vector
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
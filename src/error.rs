@@ -0,0 +1,33 @@
+use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
+use serde::Serialize;
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ErrorResponse {
+    pub message: String,
+}
+
+/// Errors surfaced by the API handlers.
+pub enum AppError {
+    /// A Qdrant operation (upsert or search) failed.
+    Qdrant(String),
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let message = match self {
+            AppError::Qdrant(message) => message,
+        };
+
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse { message }),
+        )
+            .into_response()
+    }
+}
+
+impl From<qdrant_client::QdrantError> for AppError {
+    fn from(err: qdrant_client::QdrantError) -> Self {
+        AppError::Qdrant(err.to_string())
+    }
+}
@@ -1,8 +1,30 @@
-use axum::{routing::get, Router, Json};
-use utoipa::OpenApi;
-use utoipa_swagger_ui::SwaggerUi;
+mod embedding;
+mod error;
+mod qdrant;
+mod search;
+
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Json, Router,
+};
+use qdrant_client::Qdrant;
 use serde::Serialize;
 use tokio::net::TcpListener;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::qdrant::SearchIndex;
+use crate::search::{
+    add_document_handler, search_handler, DocumentRequest, DocumentResponse, SearchRequest,
+    SearchResult,
+};
+
+/// State shared across all handlers.
+struct AppState {
+    index: SearchIndex,
+}
 
 #[derive(Serialize, utoipa::ToSchema)]
 struct HealthResponse {
@@ -25,10 +47,17 @@ async fn health_handler() -> Json<HealthResponse> {
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(health_handler),
-    components(schemas(HealthResponse)),
+    paths(health_handler, add_document_handler, search_handler),
+    components(schemas(
+        HealthResponse,
+        DocumentRequest,
+        DocumentResponse,
+        SearchRequest,
+        SearchResult
+    )),
     tags(
-        (name = "Health", description = "Health check endpoints")
+        (name = "Health", description = "Health check endpoints"),
+        (name = "Search", description = "Document ingestion and semantic search")
     ),
     info(
         title = "SEO Engine API",
@@ -40,8 +69,25 @@ struct ApiDoc;
 
 #[tokio::main]
 async fn main() {
+    let qdrant_url =
+        std::env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".to_string());
+    let client = Qdrant::from_url(&qdrant_url)
+        .build()
+        .expect("failed to build Qdrant client");
+
+    let index = SearchIndex::new(client);
+    index
+        .ensure_collection()
+        .await
+        .expect("failed to ensure Qdrant collection exists");
+
+    let state = Arc::new(AppState { index });
+
     let app = Router::new()
         .route("/health", get(health_handler))
+        .route("/documents", post(add_document_handler))
+        .route("/search", post(search_handler))
+        .with_state(state)
         .merge(SwaggerUi::new("/docs")
             .url("/api-docs/openapi.json", ApiDoc::openapi()));
 
@@ -55,6 +101,8 @@ async fn main() {
     println!("🚀 Server running at http://{}", addr);
     println!("📖 API endpoints:");
     println!("   - Health: http://{}/health", addr);
+    println!("   - Documents: http://{}/documents", addr);
+    println!("   - Search: http://{}/search", addr);
     println!("   - API docs: http://{}/docs", addr);
 
     axum::serve(TcpListener::bind(&addr).await.unwrap(), app)
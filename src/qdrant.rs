@@ -0,0 +1,89 @@
+use qdrant_client::qdrant::{
+    CreateCollectionBuilder, Distance, PointStruct, ScoredPoint, SearchPointsBuilder,
+    UpsertPointsBuilder, VectorParamsBuilder,
+};
+use qdrant_client::{Payload, Qdrant, QdrantError};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::embedding::VECTOR_SIZE;
+
+/// Name of the Qdrant collection documents are ingested into and searched from.
+pub const COLLECTION_NAME: &str = "documents";
+
+/// Thin wrapper around a `qdrant_client::Qdrant` handle that knows about
+/// this service's single collection.
+pub struct SearchIndex {
+    client: Qdrant,
+}
+
+impl SearchIndex {
+    pub fn new(client: Qdrant) -> Self {
+        Self { client }
+    }
+
+    /// Creates the documents collection if it doesn't already exist.
+    pub async fn ensure_collection(&self) -> Result<(), QdrantError> {
+        if self.client.collection_exists(COLLECTION_NAME).await? {
+            return Ok(());
+        }
+
+        self.client
+            .create_collection(
+                CreateCollectionBuilder::new(COLLECTION_NAME)
+                    .vectors_config(VectorParamsBuilder::new(VECTOR_SIZE, Distance::Cosine)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Upserts a document with its precomputed embedding `vector`, returning
+    /// the id it was stored under.
+    pub async fn upsert_document(
+        &self,
+        url: &str,
+        title: &str,
+        body: &str,
+        vector: Vec<f32>,
+    ) -> Result<Uuid, QdrantError> {
+        let id = Uuid::new_v4();
+        let snippet: String = body.chars().take(280).collect();
+
+        let payload: Payload = json!({
+            "url": url,
+            "title": title,
+            "snippet": snippet,
+        })
+        .try_into()
+        .expect("payload is a JSON object");
+
+        let point = PointStruct::new(id.to_string(), vector, payload);
+
+        self.client
+            .upsert_points(UpsertPointsBuilder::new(COLLECTION_NAME, vec![point]))
+            .await?;
+
+        Ok(id)
+    }
+
+    /// Returns the `limit` nearest documents to `vector` scoring at or
+    /// above `score_threshold`, sorted by descending cosine similarity.
+    pub async fn search(
+        &self,
+        vector: Vec<f32>,
+        limit: u64,
+        score_threshold: f32,
+    ) -> Result<Vec<ScoredPoint>, QdrantError> {
+        let response = self
+            .client
+            .search_points(
+                SearchPointsBuilder::new(COLLECTION_NAME, vector, limit)
+                    .score_threshold(score_threshold)
+                    .with_payload(true),
+            )
+            .await?;
+
+        Ok(response.result)
+    }
+}
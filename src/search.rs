@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::embedding::embed;
+use crate::error::AppError;
+use crate::AppState;
+
+#[derive(Deserialize, utoipa::ToSchema)]
+pub struct DocumentRequest {
+    pub url: String,
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct DocumentResponse {
+    pub id: Uuid,
+}
+
+#[derive(Deserialize, utoipa::ToSchema)]
+pub struct SearchRequest {
+    pub query: String,
+    /// Maximum number of results to return. Defaults to 10.
+    #[serde(default = "default_limit")]
+    pub limit: u64,
+    /// Minimum cosine similarity a result must meet to be returned. Defaults to 0.0.
+    #[serde(default)]
+    pub score_threshold: f32,
+}
+
+fn default_limit() -> u64 {
+    10
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct SearchResult {
+    pub url: String,
+    pub title: String,
+    pub snippet: String,
+    pub score: f32,
+}
+
+/// Adds a document to the search index.
+#[utoipa::path(
+    post,
+    path = "/documents",
+    request_body = DocumentRequest,
+    responses(
+        (status = 200, description = "Document ingested", body = DocumentResponse)
+    ),
+    tag = "Search"
+)]
+pub async fn add_document_handler(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<DocumentRequest>,
+) -> Result<Json<DocumentResponse>, AppError> {
+    let vector = embed(&format!("{} {}", request.title, request.body));
+
+    let id = state
+        .index
+        .upsert_document(&request.url, &request.title, &request.body, vector)
+        .await?;
+
+    Ok(Json(DocumentResponse { id }))
+}
+
+/// Searches for documents semantically similar to the query text.
+#[utoipa::path(
+    post,
+    path = "/search",
+    request_body = SearchRequest,
+    responses(
+        (status = 200, description = "Matching documents, most similar first", body = [SearchResult])
+    ),
+    tag = "Search"
+)]
+pub async fn search_handler(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SearchRequest>,
+) -> Result<Json<Vec<SearchResult>>, AppError> {
+    let vector = embed(&request.query);
+
+    let points = state
+        .index
+        .search(vector, request.limit, request.score_threshold)
+        .await?;
+
+    let results = points
+        .into_iter()
+        .map(|point| {
+            let payload = point.payload;
+            SearchResult {
+                url: string_field(&payload, "url"),
+                title: string_field(&payload, "title"),
+                snippet: string_field(&payload, "snippet"),
+                score: point.score,
+            }
+        })
+        .collect();
+
+    Ok(Json(results))
+}
+
+fn string_field(payload: &std::collections::HashMap<String, qdrant_client::qdrant::Value>, key: &str) -> String {
+    payload
+        .get(key)
+        .and_then(|value| value.as_str())
+        .unwrap_or_default()
+        .to_string()
+}